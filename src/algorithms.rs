@@ -0,0 +1,261 @@
+//! # Algorithms
+//!
+//! This module provides ready-made similarity algorithms that implement the
+//! traits in this crate, so you can use common string metrics without having
+//! to write the comparison code yourself.
+//!
+//! Each algorithm is a zero-sized type that implements
+//! [`SimilarityIO`](crate::SimilarityIO) for a pair of string slices, the
+//! same pattern shown in the crate-level documentation.
+//!
+//! Example: given two strings, then return the Jaro similarity.
+//!
+//! ```rust
+//! use similarity_trait::*;
+//! use similarity_trait::algorithms::JaroSimilarity;
+//!
+//! let similarity = JaroSimilarity::similarity(("martha", "marhta"));
+//! assert!(similarity > 0.944 && similarity < 0.945);
+//! ```
+
+use crate::SimilarityIO;
+
+/// Jaro similarity of a pair of strings.
+///
+/// The Jaro similarity counts matching characters within a sliding window and
+/// penalizes transpositions of matched characters. The result is in the range
+/// `0.0..=1.0`, where `1.0` means the strings are identical.
+pub struct JaroSimilarity;
+
+impl SimilarityIO<(&str, &str), f64> for JaroSimilarity {
+    /// Similarity of a pair of strings via Jaro similarity.
+    fn similarity(input: (&str, &str)) -> f64 {
+        jaro(input.0, input.1)
+    }
+}
+
+/// Jaro-Winkler similarity of a pair of strings.
+///
+/// This is the Jaro similarity with a bonus for strings that share a common
+/// prefix, which rewards the kind of near-matches common in typos.
+pub struct JaroWinklerSimilarity;
+
+impl SimilarityIO<(&str, &str), f64> for JaroWinklerSimilarity {
+    /// Similarity of a pair of strings via Jaro-Winkler similarity.
+    fn similarity(input: (&str, &str)) -> f64 {
+        let jaro = jaro(input.0, input.1);
+        let prefix_len = input
+            .0
+            .chars()
+            .zip(input.1.chars())
+            .take(4)
+            .take_while(|(c1, c2)| c1 == c2)
+            .count() as f64;
+        jaro + (prefix_len * 0.1 * (1.0 - jaro))
+    }
+}
+
+/// Shared Jaro similarity calculation used by `JaroSimilarity` and
+/// `JaroWinklerSimilarity`.
+fn jaro(str1: &str, str2: &str) -> f64 {
+    let chars1: Vec<char> = str1.chars().collect();
+    let chars2: Vec<char> = str2.chars().collect();
+    let len1 = chars1.len();
+    let len2 = chars2.len();
+
+    if len1 == 0 && len2 == 0 {
+        return 1.0;
+    }
+    if len1 == 0 || len2 == 0 {
+        return 0.0;
+    }
+
+    let match_window = (std::cmp::max(len1, len2) / 2).saturating_sub(1);
+    let mut matched1 = vec![false; len1];
+    let mut matched2 = vec![false; len2];
+    let mut m = 0;
+
+    for i in 0..len1 {
+        let start = i.saturating_sub(match_window);
+        let end = std::cmp::min(i + match_window + 1, len2);
+        for j in start..end {
+            if !matched2[j] && chars1[i] == chars2[j] {
+                matched1[i] = true;
+                matched2[j] = true;
+                m += 1;
+                break;
+            }
+        }
+    }
+
+    if m == 0 {
+        return 0.0;
+    }
+
+    let mut t = 0;
+    let mut k = 0;
+    for i in 0..len1 {
+        if matched1[i] {
+            while !matched2[k] {
+                k += 1;
+            }
+            if chars1[i] != chars2[k] {
+                t += 1;
+            }
+            k += 1;
+        }
+    }
+    let t = t as f64 / 2.0;
+    let m = m as f64;
+
+    ((m / len1 as f64) + (m / len2 as f64) + ((m - t) / m)) / 3.0
+}
+
+/// Damerau-Levenshtein distance of a pair of strings.
+///
+/// This is the Levenshtein edit distance extended to also count adjacent
+/// transpositions (e.g. "ab" to "ba") as a single edit.
+pub struct DamerauLevenshtein;
+
+impl SimilarityIO<(&str, &str), usize> for DamerauLevenshtein {
+    /// Similarity of a pair of strings via Damerau-Levenshtein distance.
+    fn similarity(input: (&str, &str)) -> usize {
+        let (str1, str2) = input;
+        let chars1: Vec<char> = str1.chars().collect();
+        let chars2: Vec<char> = str2.chars().collect();
+        let len1 = chars1.len();
+        let len2 = chars2.len();
+
+        if len1 == 0 {
+            return len2;
+        }
+        if len2 == 0 {
+            return len1;
+        }
+
+        let mut matrix = vec![vec![0; len2 + 1]; len1 + 1];
+
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[0] = i;
+        }
+        for (j, cell) in matrix[0].iter_mut().enumerate() {
+            *cell = j;
+        }
+
+        for i in 1..=len1 {
+            for j in 1..=len2 {
+                let cost = if chars1[i - 1] == chars2[j - 1] { 0 } else { 1 };
+                matrix[i][j] = (matrix[i - 1][j] + 1) // deletion
+                    .min(matrix[i][j - 1] + 1) // insertion
+                    .min(matrix[i - 1][j - 1] + cost); // substitution
+                if i > 1
+                    && j > 1
+                    && chars1[i - 1] == chars2[j - 2]
+                    && chars1[i - 2] == chars2[j - 1]
+                {
+                    matrix[i][j] = matrix[i][j].min(matrix[i - 2][j - 2] + 1); // transposition
+                }
+            }
+        }
+
+        matrix[len1][len2]
+    }
+}
+
+/// Jaccard similarity of a pair of strings via character n-grams.
+///
+/// The strings are split into overlapping character bigrams (n-grams of
+/// length 2), and the similarity is the size of the intersection of the two
+/// bigram sets divided by the size of their union.
+pub struct JaccardSimilarity;
+
+impl SimilarityIO<(&str, &str), f64> for JaccardSimilarity {
+    /// Similarity of a pair of strings via Jaccard similarity of bigrams.
+    fn similarity(input: (&str, &str)) -> f64 {
+        const N: usize = 2;
+        let ngrams = |s: &str| -> std::collections::HashSet<Vec<char>> {
+            let chars: Vec<char> = s.chars().collect();
+            if chars.len() < N {
+                return std::collections::HashSet::from([chars]);
+            }
+            chars.windows(N).map(|w| w.to_vec()).collect()
+        };
+        let set1 = ngrams(input.0);
+        let set2 = ngrams(input.1);
+        let intersection = set1.intersection(&set2).count();
+        let union = set1.union(&set2).count();
+        if union == 0 {
+            return 1.0;
+        }
+        intersection as f64 / union as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod jaro_similarity {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let similarity = JaroSimilarity::similarity(("martha", "marhta"));
+            assert!(similarity > 0.944 && similarity < 0.945);
+        }
+
+        #[test]
+        fn test_identical() {
+            let similarity = JaroSimilarity::similarity(("information", "information"));
+            assert_eq!(similarity, 1.0);
+        }
+
+        #[test]
+        fn test_no_match() {
+            let similarity = JaroSimilarity::similarity(("abc", "xyz"));
+            assert_eq!(similarity, 0.0);
+        }
+    }
+
+    mod jaro_winkler_similarity {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let similarity = JaroWinklerSimilarity::similarity(("martha", "marhta"));
+            assert!(similarity > 0.961 && similarity < 0.962);
+        }
+    }
+
+    mod damerau_levenshtein {
+        use super::*;
+
+        #[test]
+        fn test_transposition() {
+            let distance = DamerauLevenshtein::similarity(("ab", "ba"));
+            assert_eq!(distance, 1);
+        }
+
+        #[test]
+        fn test_insertion() {
+            let distance = DamerauLevenshtein::similarity(("inform", "information"));
+            assert_eq!(distance, 5);
+        }
+    }
+
+    mod jaccard_similarity {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let similarity = JaccardSimilarity::similarity(("night", "nacht"));
+            assert!(similarity > 0.142 && similarity < 0.143);
+        }
+
+        #[test]
+        fn test_identical() {
+            let similarity = JaccardSimilarity::similarity(("informatics", "informatics"));
+            assert_eq!(similarity, 1.0);
+        }
+    }
+}
@@ -209,6 +209,13 @@ pub trait SimilaritySIO<Input, Output> {
     fn similarity(&self, input: Input) -> Output;
 }
 
+pub mod algorithms;
+pub mod similarity_rank;
+pub mod similarity_normalized;
+pub mod similarity_try;
+pub mod simhash;
+pub mod similarity_matrix;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,141 @@
+//! # SimHash
+//!
+//! SimHash is a locality-sensitive hash: similar documents (token streams)
+//! produce similar fingerprints, so near-duplicate detection becomes a cheap
+//! Hamming distance over 64-bit integers instead of a full document
+//! comparison. This follows the feature-accumulation approach of the simhash
+//! crate.
+//!
+//! The hasher used to turn each token into a `u64` is pluggable via the type
+//! parameter `H`; it defaults to [`DefaultHasher`] (SipHash), and
+//! [`FnvHasher`] is provided as a faster, non-cryptographic alternative.
+//!
+//! Example: given two similar token streams, then return a similarity close
+//! to `1.0`.
+//!
+//! ```rust
+//! use similarity_trait::*;
+//! use similarity_trait::simhash::SimHash;
+//!
+//! let document0 = ["the", "quick", "brown", "fox"];
+//! let document1 = ["the", "quick", "brown", "dog"];
+//! let similarity = SimHash::<std::collections::hash_map::DefaultHasher>::similarity(&document0[..], &document1[..]);
+//! assert!(similarity > 0.5);
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// SimHash fingerprint and similarity calculator, parameterized by the
+/// `Hasher` used to turn each token into a `u64`. Defaults to
+/// [`DefaultHasher`] (SipHash).
+pub struct SimHash<H = DefaultHasher> {
+    hasher: PhantomData<H>,
+}
+
+impl<H: Hasher + Default> SimHash<H> {
+    /// Compute the 64-bit SimHash fingerprint of a token stream.
+    ///
+    /// Each token is hashed with `H`, and every bit position of the hash
+    /// casts a `+1`/`-1` vote into a 64-slot accumulator; the fingerprint bit
+    /// `i` is `1` iff accumulator slot `i` ends up positive.
+    pub fn fingerprint<T: Hash>(tokens: impl IntoIterator<Item = T>) -> u64 {
+        let mut accumulator = [0i64; 64];
+        for token in tokens {
+            let mut hasher = H::default();
+            token.hash(&mut hasher);
+            let hash = hasher.finish();
+            for (i, vote) in accumulator.iter_mut().enumerate() {
+                if hash & (1 << i) != 0 {
+                    *vote += 1;
+                } else {
+                    *vote -= 1;
+                }
+            }
+        }
+        let mut fingerprint = 0u64;
+        for (i, vote) in accumulator.iter().enumerate() {
+            if *vote > 0 {
+                fingerprint |= 1 << i;
+            }
+        }
+        fingerprint
+    }
+}
+
+impl<H: Hasher + Default> crate::SimilarityIIO<u64, u64, f64> for SimHash<H> {
+    /// Similarity of two precomputed SimHash fingerprints: `1.0` minus the
+    /// fraction of differing bits.
+    fn similarity(fingerprint0: u64, fingerprint1: u64) -> f64 {
+        1.0 - (fingerprint0 ^ fingerprint1).count_ones() as f64 / 64.0
+    }
+}
+
+impl<H: Hasher + Default, T: Hash> crate::SimilarityIIO<&[T], &[T], f64> for SimHash<H> {
+    /// Similarity of two documents, given as token slices: each document is
+    /// fingerprinted, then the fingerprints are compared.
+    fn similarity(document0: &[T], document1: &[T]) -> f64 {
+        let fingerprint0 = Self::fingerprint(document0.iter());
+        let fingerprint1 = Self::fingerprint(document1.iter());
+        <Self as crate::SimilarityIIO<u64, u64, f64>>::similarity(fingerprint0, fingerprint1)
+    }
+}
+
+/// FNV-1a 64-bit hasher, a faster non-cryptographic alternative to the
+/// default SipHash, useful when SimHash is run over large corpora.
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        const FNV_PRIME: u64 = 0x100000001b3;
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimilarityIIO;
+
+    #[test]
+    fn test_identical_documents_are_maximally_similar() {
+        let document = ["the", "quick", "brown", "fox"];
+        let fingerprint = SimHash::<DefaultHasher>::fingerprint(document.iter());
+        let similarity = SimHash::<DefaultHasher>::similarity(fingerprint, fingerprint);
+        assert_eq!(similarity, 1.0);
+    }
+
+    #[test]
+    fn test_similar_documents_are_closer_than_different_documents() {
+        let document0 = ["the", "quick", "brown", "fox"];
+        let document1 = ["the", "quick", "brown", "dog"];
+        let document2 = ["lorem", "ipsum", "dolor", "sit"];
+
+        let similar = SimHash::<DefaultHasher>::similarity(&document0[..], &document1[..]);
+        let different = SimHash::<DefaultHasher>::similarity(&document0[..], &document2[..]);
+        assert!(similar > different);
+    }
+
+    #[test]
+    fn test_fnv_hasher_agrees_with_itself() {
+        let document = ["the", "quick", "brown", "fox"];
+        let fingerprint0 = SimHash::<FnvHasher>::fingerprint(document.iter());
+        let fingerprint1 = SimHash::<FnvHasher>::fingerprint(document.iter());
+        assert_eq!(fingerprint0, fingerprint1);
+    }
+}
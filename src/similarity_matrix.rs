@@ -0,0 +1,211 @@
+//! # Similarity matrix
+//!
+//! The intra-group similarity examples elsewhere in this crate hand-roll
+//! nested `for` loops to find the maximum pairwise distance, discarding
+//! every other pair's score along the way. This module keeps the whole
+//! pairwise structure instead of collapsing it to one scalar.
+//!
+//! `SimilarityMatrix::build` takes a slice of items and any pairwise
+//! [`SimilarityIIO<&T, &T, Score>`](crate::SimilarityIIO) implementation,
+//! and produces a symmetric N×N matrix, stored compactly as just the upper
+//! triangle (since `similarity(a, b) == similarity(b, a)` and the diagonal
+//! is always "compared to itself").
+//!
+//! Example: given a collection of strings, then build the Jaro-similarity
+//! matrix and find the most similar pair. `most_similar_pair` picks the
+//! highest score, so the per-pair kernel should return similarity (higher
+//! means more alike), not distance.
+//!
+//! ```rust
+//! use similarity_trait::*;
+//! use similarity_trait::algorithms::JaroSimilarity;
+//! use similarity_trait::similarity_matrix::SimilarityMatrix;
+//!
+//! struct Jaro;
+//!
+//! impl SimilarityIIO<&&str, &&str, f64> for Jaro {
+//!     fn similarity(a: &&str, b: &&str) -> f64 {
+//!         JaroSimilarity::similarity((*a, *b))
+//!     }
+//! }
+//!
+//! let collection = vec!["information", "informatics", "affirmation"];
+//! let matrix = SimilarityMatrix::<f64>::build::<Jaro, _>(&collection);
+//! let (i, j, score) = matrix.most_similar_pair().expect("most_similar_pair");
+//! assert_eq!((i, j), (0, 1));
+//! assert!(score > 0.85);
+//! ```
+
+/// A symmetric pairwise similarity matrix over `n` items, stored compactly
+/// as its upper triangle (the `n * (n - 1) / 2` pairs with `i < j`).
+pub struct SimilarityMatrix<Score> {
+    n: usize,
+    values: Vec<Score>,
+}
+
+impl<Score: Copy> SimilarityMatrix<Score> {
+    /// Build the pairwise similarity matrix for `items`, using `S` as the
+    /// per-pair kernel.
+    pub fn build<'a, S, T>(items: &'a [T]) -> Self
+    where
+        S: crate::SimilarityIIO<&'a T, &'a T, Score>,
+    {
+        let n = items.len();
+        let mut values = Vec::with_capacity(n.saturating_sub(1) * n / 2);
+        for i in 0..n {
+            for j in (i + 1)..n {
+                values.push(S::similarity(&items[i], &items[j]));
+            }
+        }
+        Self { n, values }
+    }
+
+    /// Number of items the matrix was built over.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Whether the matrix has fewer than two items, and so has no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.n < 2
+    }
+
+    /// The similarity score for items `i` and `j` (order doesn't matter),
+    /// or `None` if `i == j` or either index is out of range.
+    pub fn get(&self, i: usize, j: usize) -> Option<Score> {
+        if i >= self.n || j >= self.n || i == j {
+            return None;
+        }
+        let (low, high) = if i < j { (i, j) } else { (j, i) };
+        Some(self.values[self.index(low, high)])
+    }
+
+    /// Iterate over every pair as `(i, j, score)` with `i < j`.
+    pub fn pairs(&self) -> impl Iterator<Item = (usize, usize, Score)> + '_ {
+        (0..self.n)
+            .flat_map(move |i| ((i + 1)..self.n).map(move |j| (i, j)))
+            .zip(self.values.iter().copied())
+            .map(|((i, j), score)| (i, j, score))
+    }
+
+    /// Position of pair `(i, j)` (with `i < j`) in the compact upper-triangle
+    /// storage.
+    fn index(&self, i: usize, j: usize) -> usize {
+        i * self.n - i * (i + 1) / 2 + (j - i - 1)
+    }
+}
+
+impl<Score: Copy + PartialOrd> SimilarityMatrix<Score> {
+    /// The maximum pairwise score, or `None` if there are fewer than two
+    /// items.
+    pub fn max(&self) -> Option<Score> {
+        self.values
+            .iter()
+            .copied()
+            .fold(None, |max, score| match max {
+                None => Some(score),
+                Some(max) if score > max => Some(score),
+                Some(max) => Some(max),
+            })
+    }
+
+    /// The minimum pairwise score, or `None` if there are fewer than two
+    /// items.
+    pub fn min(&self) -> Option<Score> {
+        self.values
+            .iter()
+            .copied()
+            .fold(None, |min, score| match min {
+                None => Some(score),
+                Some(min) if score < min => Some(score),
+                Some(min) => Some(min),
+            })
+    }
+
+    /// The `(i, j, score)` pair with the highest score, or `None` if there
+    /// are fewer than two items.
+    pub fn most_similar_pair(&self) -> Option<(usize, usize, Score)> {
+        self.pairs()
+            .fold(None, |best, (i, j, score)| match best {
+                None => Some((i, j, score)),
+                Some((_, _, best_score)) if score > best_score => Some((i, j, score)),
+                Some(best) => Some(best),
+            })
+    }
+}
+
+impl<Score: Copy + Into<f64>> SimilarityMatrix<Score> {
+    /// The mean of all pairwise scores, or `None` if there are fewer than
+    /// two items.
+    pub fn mean(&self) -> Option<f64> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let sum: f64 = self.values.iter().copied().map(Into::into).sum();
+        Some(sum / self.values.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SimilarityIIO, SimilarityIO};
+
+    struct HammingDistance;
+
+    impl SimilarityIIO<&&str, &&str, usize> for HammingDistance {
+        fn similarity(a: &&str, b: &&str) -> usize {
+            a.chars().zip(b.chars()).filter(|(c1, c2)| c1 != c2).count()
+        }
+    }
+
+    #[test]
+    fn test_max_and_min() {
+        let collection = vec!["information", "informatics", "affirmation"];
+        let matrix = SimilarityMatrix::<usize>::build::<HammingDistance, _>(&collection);
+        assert_eq!(matrix.max(), Some(5));
+        assert_eq!(matrix.min(), Some(2));
+    }
+
+    struct Jaro;
+
+    impl SimilarityIIO<&&str, &&str, f64> for Jaro {
+        fn similarity(a: &&str, b: &&str) -> f64 {
+            crate::algorithms::JaroSimilarity::similarity((*a, *b))
+        }
+    }
+
+    #[test]
+    fn test_most_similar_pair() {
+        let collection = vec!["information", "informatics", "affirmation"];
+        let matrix = SimilarityMatrix::<f64>::build::<Jaro, _>(&collection);
+        let (i, j, score) = matrix.most_similar_pair().expect("most_similar_pair");
+        assert_eq!((i, j), (0, 1));
+        assert!(score > 0.85);
+    }
+
+    #[test]
+    fn test_get_is_symmetric() {
+        let collection = vec!["information", "informatics", "affirmation"];
+        let matrix = SimilarityMatrix::<usize>::build::<HammingDistance, _>(&collection);
+        assert_eq!(matrix.get(0, 1), matrix.get(1, 0));
+        assert_eq!(matrix.get(0, 0), None);
+    }
+
+    #[test]
+    fn test_mean() {
+        let collection = vec!["information", "informatics", "affirmation"];
+        let matrix = SimilarityMatrix::<f64>::build::<Jaro, _>(&collection);
+        let mean = matrix.mean().expect("mean");
+        assert!(mean > 0.0);
+    }
+
+    #[test]
+    fn test_single_item_has_no_pairs() {
+        let collection = vec!["information"];
+        let matrix = SimilarityMatrix::<f64>::build::<Jaro, _>(&collection);
+        assert!(matrix.is_empty());
+        assert_eq!(matrix.max(), None);
+        assert_eq!(matrix.mean(), None);
+    }
+}
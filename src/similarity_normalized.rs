@@ -0,0 +1,117 @@
+//! # Similarity normalized
+//!
+//! Many metrics in this crate return raw counts (Hamming distance,
+//! Levenshtein distance) that aren't comparable across inputs of different
+//! lengths: a distance of `2` out of `5` characters is not as similar as a
+//! distance of `2` out of `50` characters.
+//!
+//! This module provides a trait to normalize a raw distance into a relative
+//! distance and a complementary similarity, both bounded in `[0.0, 1.0]`,
+//! mirroring eddie's `rel_dist`/`similarity` complementary-metric design.
+//!
+//! Example: given a pair of strings, then return the Hamming distance
+//! normalized by the length of the longer string.
+//!
+//! ```rust
+//! use similarity_trait::*;
+//! use similarity_trait::similarity_normalized::SimilarityNormalized;
+//!
+//! struct HammingDistance;
+//!
+//! impl SimilarityIO<(&str, &str), usize> for HammingDistance {
+//!     fn similarity(input: (&str, &str)) -> usize {
+//!         input.0.chars().zip(input.1.chars()).filter(|(c1, c2)| c1 != c2).count()
+//!     }
+//! }
+//!
+//! impl SimilarityNormalized<(&str, &str)> for HammingDistance {
+//!     fn max_len(input: (&str, &str)) -> usize {
+//!         std::cmp::max(input.0.chars().count(), input.1.chars().count())
+//!     }
+//! }
+//!
+//! let pair = ("information", "informatics");
+//! let rel_distance = HammingDistance::rel_distance(pair).expect("rel_distance");
+//! assert!(rel_distance > 0.181 && rel_distance < 0.182);
+//! let similarity = HammingDistance::similarity_normalized(pair).expect("similarity_normalized");
+//! assert!(similarity > 0.818 && similarity < 0.819);
+//! ```
+
+use crate::SimilarityIO;
+
+/// SimilarityNormalized trait for Input.
+///
+/// SimilarityNormalized lifts a [`SimilarityIO<Input, usize>`](crate::SimilarityIO)
+/// raw-distance implementation into a length-invariant form. Implementors
+/// supply `max_len`, the length to divide the raw distance by; `rel_distance`
+/// and `similarity_normalized` are derived from it.
+pub trait SimilarityNormalized<Input>: SimilarityIO<Input, usize> {
+    /// The length to divide the raw distance by, typically the length of the
+    /// longer input.
+    fn max_len(input: Input) -> usize;
+
+    /// Relative distance: the raw distance divided by `max_len`, in
+    /// `[0.0, 1.0]`. Returns `None` if `max_len` is `0`.
+    fn rel_distance(input: Input) -> Option<f64>
+    where
+        Input: Copy,
+    {
+        let max_len = Self::max_len(input);
+        if max_len == 0 {
+            return None;
+        }
+        Some(Self::similarity(input) as f64 / max_len as f64)
+    }
+
+    /// Normalized similarity: `1.0 - rel_distance`, in `[0.0, 1.0]`. Returns
+    /// `None` if `max_len` is `0`.
+    fn similarity_normalized(input: Input) -> Option<f64>
+    where
+        Input: Copy,
+    {
+        Some(1.0 - Self::rel_distance(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct HammingDistance;
+
+    impl SimilarityIO<(&str, &str), usize> for HammingDistance {
+        fn similarity(input: (&str, &str)) -> usize {
+            input.0.chars().zip(input.1.chars()).filter(|(c1, c2)| c1 != c2).count()
+        }
+    }
+
+    impl SimilarityNormalized<(&str, &str)> for HammingDistance {
+        fn max_len(input: (&str, &str)) -> usize {
+            std::cmp::max(input.0.chars().count(), input.1.chars().count())
+        }
+    }
+
+    #[test]
+    fn test_rel_distance() {
+        let rel_distance = HammingDistance::rel_distance(("information", "informatics")).expect("rel_distance");
+        assert!(rel_distance > 0.181 && rel_distance < 0.182);
+    }
+
+    #[test]
+    fn test_similarity_normalized() {
+        let similarity = HammingDistance::similarity_normalized(("information", "informatics")).expect("similarity_normalized");
+        assert!(similarity > 0.818 && similarity < 0.819);
+    }
+
+    #[test]
+    fn test_identical_strings_have_zero_rel_distance() {
+        let rel_distance = HammingDistance::rel_distance(("information", "information")).expect("rel_distance");
+        assert_eq!(rel_distance, 0.0);
+    }
+
+    #[test]
+    fn test_empty_inputs_return_none() {
+        assert_eq!(HammingDistance::rel_distance(("", "")), None);
+        assert_eq!(HammingDistance::similarity_normalized(("", "")), None);
+    }
+}
@@ -0,0 +1,218 @@
+//! # Similarity rank
+//!
+//! This module provides a trait for one-to-many similarity queries: given a
+//! query and many candidate items, return the `limit` most-similar items
+//! sorted best-first.
+//!
+//! Example: given a query string and a collection of candidate strings, then
+//! return the two candidates with the most equivalent characters. Note that
+//! `Score` is expected to already be oriented so that a higher score means
+//! more similar, the same convention `similarity` uses elsewhere in this
+//! crate.
+//!
+//! ```rust
+//! use similarity_trait::*;
+//! use similarity_trait::similarity_rank::SimilarityRank;
+//!
+//! struct EquivalentCharacters;
+//!
+//! impl SimilarityIIO<&str, &str, usize> for EquivalentCharacters {
+//!     fn similarity(query: &str, item: &str) -> usize {
+//!         query.chars().zip(item.chars()).filter(|(c1, c2)| c1 == c2).count()
+//!     }
+//! }
+//!
+//! impl SimilarityRank<&str, &str, usize> for EquivalentCharacters {}
+//!
+//! let candidates = vec!["informatics", "affirmation", "information"];
+//! let ranked = EquivalentCharacters::rank("information", candidates, 2);
+//! assert_eq!(ranked, vec!["information", "informatics"]);
+//! ```
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// SimilarityRank trait for Query, Item, Score.
+///
+/// SimilarityRank builds on [`SimilarityIIO`](crate::SimilarityIIO): given a
+/// query, an iterator of candidate items, and a `limit`, it returns the
+/// `limit` most-similar items sorted best-first (highest score first).
+///
+/// `Score` must implement `Ord`. Integer scores (such as a count of
+/// equivalent characters) already do. Floating-point scores don't implement
+/// `Ord` because of `NaN`, so wrap them in [`OrderedScore`] first.
+///
+/// The default implementation keeps a bounded min-heap of size `limit`, so
+/// ranking `n` candidates down to `limit` results costs `O(n log limit)`
+/// rather than sorting the whole candidate set.
+pub trait SimilarityRank<Query, Item, Score>: crate::SimilarityIIO<Query, Item, Score>
+where
+    Query: Copy,
+    Item: Copy,
+    Score: Ord,
+{
+    /// Rank candidate items by similarity to `query`, returning the `limit`
+    /// most-similar items sorted best-first.
+    fn rank<I>(query: Query, candidates: I, limit: usize) -> Vec<Item>
+    where
+        I: IntoIterator<Item = Item>,
+    {
+        if limit == 0 {
+            return Vec::new();
+        }
+
+        let mut heap: BinaryHeap<Reverse<ScoredItem<Item, Score>>> = BinaryHeap::with_capacity(limit);
+
+        for (index, item) in candidates.into_iter().enumerate() {
+            let score = Self::similarity(query, item);
+            let scored = ScoredItem { score, index, item };
+            if heap.len() < limit {
+                heap.push(Reverse(scored));
+            } else if let Some(Reverse(worst)) = heap.peek() {
+                if scored.score > worst.score {
+                    heap.pop();
+                    heap.push(Reverse(scored));
+                }
+            }
+        }
+
+        let mut scored: Vec<ScoredItem<Item, Score>> = heap.into_iter().map(|Reverse(s)| s).collect();
+        scored.sort_by(|a, b| b.cmp(a));
+        scored.into_iter().map(|s| s.item).collect()
+    }
+}
+
+/// A candidate item paired with its similarity score, ordered by score and
+/// then by its original index so that ties break deterministically.
+struct ScoredItem<Item, Score> {
+    score: Score,
+    index: usize,
+    item: Item,
+}
+
+impl<Item, Score: PartialEq> PartialEq for ScoredItem<Item, Score> {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score && self.index == other.index
+    }
+}
+
+impl<Item, Score: Eq> Eq for ScoredItem<Item, Score> {}
+
+impl<Item, Score: Ord> PartialOrd for ScoredItem<Item, Score> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Item, Score: Ord> Ord for ScoredItem<Item, Score> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .cmp(&other.score)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+/// A floating-point score that rejects `NaN`, so it has a total ordering and
+/// can be used as `Score` in [`SimilarityRank`].
+///
+/// This mirrors `ordered_float::NotNan`, scoped down to just what this crate
+/// needs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderedScore(f64);
+
+impl OrderedScore {
+    /// Create an `OrderedScore`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is `NaN`, since `NaN` has no defined ordering.
+    pub fn new(value: f64) -> Self {
+        assert!(!value.is_nan(), "OrderedScore does not accept NaN");
+        Self(value)
+    }
+
+    /// Return the wrapped value.
+    pub fn into_inner(self) -> f64 {
+        self.0
+    }
+}
+
+impl Eq for OrderedScore {}
+
+impl PartialOrd for OrderedScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedScore {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).expect("OrderedScore values are never NaN")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimilarityIIO;
+
+    struct EquivalentCharacters;
+
+    impl SimilarityIIO<&str, &str, usize> for EquivalentCharacters {
+        fn similarity(query: &str, item: &str) -> usize {
+            query.chars().zip(item.chars()).filter(|(c1, c2)| c1 == c2).count()
+        }
+    }
+
+    impl SimilarityRank<&str, &str, usize> for EquivalentCharacters {}
+
+    struct JaroLike;
+
+    impl SimilarityIIO<&str, &str, OrderedScore> for JaroLike {
+        fn similarity(query: &str, item: &str) -> OrderedScore {
+            let matches = query.chars().zip(item.chars()).filter(|(c1, c2)| c1 == c2).count();
+            OrderedScore::new(matches as f64 / query.len().max(item.len()) as f64)
+        }
+    }
+
+    impl SimilarityRank<&str, &str, OrderedScore> for JaroLike {}
+
+    #[test]
+    fn test_rank_returns_best_first() {
+        let candidates = vec!["informatics", "affirmation", "information"];
+        let ranked = EquivalentCharacters::rank("information", candidates, 2);
+        assert_eq!(ranked, vec!["information", "informatics"]);
+    }
+
+    #[test]
+    fn test_rank_limit_zero() {
+        let candidates = vec!["informatics", "information"];
+        let ranked = EquivalentCharacters::rank("information", candidates, 0);
+        assert!(ranked.is_empty());
+    }
+
+    #[test]
+    fn test_rank_limit_larger_than_candidates() {
+        let candidates = vec!["informatics", "information"];
+        let ranked = EquivalentCharacters::rank("information", candidates, 10);
+        assert_eq!(ranked.len(), 2);
+    }
+
+    #[test]
+    fn test_rank_with_ordered_score() {
+        let candidates = vec!["informatics", "affirmation", "information"];
+        let ranked = JaroLike::rank("information", candidates, 1);
+        assert_eq!(ranked, vec!["information"]);
+    }
+
+    #[test]
+    fn test_ordered_score_orders_by_value() {
+        assert!(OrderedScore::new(1.0) < OrderedScore::new(2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn test_ordered_score_rejects_nan() {
+        OrderedScore::new(f64::NAN);
+    }
+}
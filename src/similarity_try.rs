@@ -0,0 +1,92 @@
+//! # Similarity try
+//!
+//! The Hamming distance implementations elsewhere in this crate silently
+//! truncate to the shorter input via `zip`, so `"martha"` vs `"march"`
+//! quietly returns a distance computed over only 5 characters. This module
+//! provides an error-aware alternative, following strsim's `HammingResult`
+//! and eddie's similar semantics, so callers can distinguish "genuinely 0
+//! differences" from "incomparable lengths".
+//!
+//! Example: given a pair of strings of equal length, then return the
+//! Hamming distance. Given a pair of strings of unequal length, then return
+//! an error.
+//!
+//! ```rust
+//! use similarity_trait::*;
+//! use similarity_trait::similarity_try::{SimilarityTry, LengthMismatch, Hamming};
+//!
+//! let hamming_distance = Hamming::try_similarity(("information", "informatics")).expect("try_similarity");
+//! assert_eq!(hamming_distance, 2);
+//!
+//! let error = Hamming::try_similarity(("martha", "march")).unwrap_err();
+//! assert_eq!(error, LengthMismatch { len0: 6, len1: 5 });
+//! ```
+
+use std::fmt;
+
+/// SimilarityTry trait for Input, Output, Error.
+///
+/// SimilarityTry is the fallible counterpart of
+/// [`SimilarityIO`](crate::SimilarityIO): `try_similarity` returns
+/// `Err(Error)` when `input` cannot be compared, instead of silently
+/// producing a misleading `Output`.
+pub trait SimilarityTry<Input, Output, Error> {
+    fn try_similarity(input: Input) -> Result<Output, Error>;
+}
+
+/// Error returned when two inputs being compared have different lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// Length of the first input.
+    pub len0: usize,
+    /// Length of the second input.
+    pub len1: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "length mismatch: {} vs {}", self.len0, self.len1)
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
+
+/// Hamming distance of a pair of strings, rejecting unequal-length inputs.
+pub struct Hamming;
+
+impl SimilarityTry<(&str, &str), usize, LengthMismatch> for Hamming {
+    /// Similarity of a pair of strings via Hamming distance.
+    ///
+    /// Returns `Err(LengthMismatch)` if the two strings differ in length.
+    fn try_similarity(input: (&str, &str)) -> Result<usize, LengthMismatch> {
+        let len0 = input.0.chars().count();
+        let len1 = input.1.chars().count();
+        if len0 != len1 {
+            return Err(LengthMismatch { len0, len1 });
+        }
+        Ok(input.0.chars().zip(input.1.chars()).filter(|(c0, c1)| c0 != c1).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_length_returns_distance() {
+        let hamming_distance = Hamming::try_similarity(("information", "informatics")).expect("try_similarity");
+        assert_eq!(hamming_distance, 2);
+    }
+
+    #[test]
+    fn test_identical_strings_return_zero() {
+        let hamming_distance = Hamming::try_similarity(("information", "information")).expect("try_similarity");
+        assert_eq!(hamming_distance, 0);
+    }
+
+    #[test]
+    fn test_unequal_length_returns_error() {
+        let error = Hamming::try_similarity(("martha", "march")).unwrap_err();
+        assert_eq!(error, LengthMismatch { len0: 6, len1: 5 });
+    }
+}